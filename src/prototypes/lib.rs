@@ -18,4 +18,4 @@ pub mod parser;
 
 // Re-export main types for convenience
 pub use lexer::{Lexer, Token, TokenKind};
-pub use parser::{parse_source, Expr, Parser, Program, Stmt};
+pub use parser::{parse_source, parse_source_with_recovery, Expr, Parser, Program, Stmt};
@@ -8,7 +8,7 @@
 /// - Recursive descent parsing design
 /// - AST structure básico
 /// - Memory usage del AST
-/// - Feasibility de error recovery (futuro)
+/// - Error recovery: sincronización en límites de statements
 ///
 /// Construcciones soportadas (~5):
 /// - let bindings: `let x = expr;`
@@ -17,7 +17,7 @@
 /// - binary expressions: `a + b`, `x == y`
 /// - literals y identificadores
 
-use crate::prototypes::lexer::{Lexer, Token, TokenKind};
+use crate::lexer::{Lexer, Token, TokenKind};
 use std::fmt;
 
 // ===== AST Node Types =====
@@ -52,6 +52,9 @@ pub enum Expr {
 
     // Block expression (for if branches)
     Block(Vec<Stmt>),
+
+    // Placeholder for a malformed expression (error recovery)
+    Error,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -83,6 +86,9 @@ pub enum Stmt {
 
     // Expression statement
     Expr(Expr),
+
+    // Placeholder for a malformed statement (error recovery)
+    Error,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -124,6 +130,7 @@ impl fmt::Display for Expr {
             Expr::Block(stmts) => {
                 write!(f, "{{ {} stmts }}", stmts.len())
             }
+            Expr::Error => write!(f, "<error>"),
         }
     }
 }
@@ -133,22 +140,113 @@ impl fmt::Display for Expr {
 pub struct Parser {
     tokens: Vec<Token>,
     current: usize,
+    errors: Vec<String>,
 }
 
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Self {
-        Parser { tokens, current: 0 }
+        Parser {
+            tokens,
+            current: 0,
+            errors: Vec::new(),
+        }
     }
 
-    /// Parse a complete program
+    /// Parse a complete program, failing with the first syntax error
     pub fn parse(&mut self) -> Result<Program, String> {
+        let (program, mut errors) = self.parse_with_recovery();
+
+        if errors.is_empty() {
+            Ok(program)
+        } else {
+            Err(errors.remove(0))
+        }
+    }
+
+    /// Parse a complete program, recovering from syntax errors
+    ///
+    /// Malformed statements are replaced by `Stmt::Error` (or a `Let` whose
+    /// value is `Expr::Error`) and parsing resumes at the next statement
+    /// boundary, so every error in the source is reported in one pass.
+    /// A statement that only lacks its trailing `;` is kept as-is.
+    pub fn parse_with_recovery(&mut self) -> (Program, Vec<String>) {
         let mut stmts = Vec::new();
 
         while !self.is_at_end() {
-            stmts.push(self.statement()?);
+            let start = self.current;
+            stmts.push(self.statement_with_recovery());
+
+            // A stray token that is also a sync point (e.g. `}`) must not stall the loop
+            if self.current == start {
+                self.advance();
+            }
         }
 
-        Ok(Program { stmts })
+        (Program { stmts }, std::mem::take(&mut self.errors))
+    }
+
+    // ===== Error Recovery =====
+
+    /// Parse a statement, recording any error and synchronizing instead of failing
+    fn statement_with_recovery(&mut self) -> Stmt {
+        match self.statement() {
+            Ok(stmt) => stmt,
+            Err(message) => {
+                self.errors.push(message);
+                self.synchronize();
+                Stmt::Error
+            }
+        }
+    }
+
+    /// Skip tokens until the next statement boundary
+    ///
+    /// Stops after a `;` or before a token that starts a statement or closes the
+    /// enclosing block. Blocks opened after the error are skipped as a whole, so
+    /// their contents are not mistaken for statements of the enclosing block.
+    fn synchronize(&mut self) {
+        let mut depth = 0usize;
+
+        while !self.is_at_end() {
+            match self.peek().kind {
+                TokenKind::LeftBrace => {
+                    depth += 1;
+                }
+                TokenKind::RightBrace if depth > 0 => {
+                    depth -= 1;
+                }
+                _ if depth > 0 => {}
+                TokenKind::Semicolon => {
+                    self.advance();
+                    return;
+                }
+                TokenKind::Let | TokenKind::Fn | TokenKind::Return | TokenKind::RightBrace => {
+                    return;
+                }
+                _ => {}
+            }
+            self.advance();
+        }
+    }
+
+    /// Consume the `;` ending a statement, recording an error if it is missing
+    ///
+    /// The statement itself parsed fine, so it is kept and parsing resumes at
+    /// the current token instead of synchronizing.
+    fn expect_semicolon(&mut self, message: &str) {
+        if let Err(error) = self.expect(TokenKind::Semicolon, message) {
+            self.errors.push(error);
+        }
+    }
+
+    /// Consume the `}` closing a block, recording an error if it is missing
+    ///
+    /// Keeps the enclosing construct and the statements parsed so far when a
+    /// block is still open at end of file.
+    fn expect_closing_brace(&mut self, message: &str) {
+        if let Err(error) = self.expect(TokenKind::RightBrace, message) {
+            self.errors.push(error);
+        }
     }
 
     // ===== Statement Parsing =====
@@ -160,7 +258,7 @@ impl Parser {
             TokenKind::Return => self.return_statement(),
             _ => {
                 let expr = self.expression()?;
-                self.expect(TokenKind::Semicolon, "Expected ';' after expression")?;
+                self.expect_semicolon("Expected ';' after expression");
                 Ok(Stmt::Expr(expr))
             }
         }
@@ -180,9 +278,20 @@ impl Parser {
 
         self.expect(TokenKind::Equal, "Expected '=' after variable name")?;
 
-        let value = self.expression()?;
+        // Keep the binding even if its initializer is malformed
+        let value = match self.expression() {
+            Ok(value) => value,
+            Err(message) => {
+                self.errors.push(message);
+                self.synchronize();
+                return Ok(Stmt::Let {
+                    name,
+                    value: Expr::Error,
+                });
+            }
+        };
 
-        self.expect(TokenKind::Semicolon, "Expected ';' after let statement")?;
+        self.expect_semicolon("Expected ';' after let statement");
 
         Ok(Stmt::Let { name, value })
     }
@@ -227,10 +336,10 @@ impl Parser {
         let mut body = Vec::new();
 
         while !matches!(self.peek().kind, TokenKind::RightBrace) && !self.is_at_end() {
-            body.push(self.statement()?);
+            body.push(self.statement_with_recovery());
         }
 
-        self.expect(TokenKind::RightBrace, "Expected '}' after function body")?;
+        self.expect_closing_brace("Expected '}' after function body");
 
         Ok(Stmt::Fn { name, params, body })
     }
@@ -244,7 +353,7 @@ impl Parser {
         }
 
         let value = self.expression()?;
-        self.expect(TokenKind::Semicolon, "Expected ';' after return value")?;
+        self.expect_semicolon("Expected ';' after return value");
 
         Ok(Stmt::Return(Some(value)))
     }
@@ -421,10 +530,10 @@ impl Parser {
 
         let mut then_stmts = Vec::new();
         while !matches!(self.peek().kind, TokenKind::RightBrace) && !self.is_at_end() {
-            then_stmts.push(self.statement()?);
+            then_stmts.push(self.statement_with_recovery());
         }
 
-        self.expect(TokenKind::RightBrace, "Expected '}' after if body")?;
+        self.expect_closing_brace("Expected '}' after if body");
 
         let else_branch = if matches!(self.peek().kind, TokenKind::Else) {
             self.advance(); // consume 'else'
@@ -433,10 +542,10 @@ impl Parser {
 
             let mut else_stmts = Vec::new();
             while !matches!(self.peek().kind, TokenKind::RightBrace) && !self.is_at_end() {
-                else_stmts.push(self.statement()?);
+                else_stmts.push(self.statement_with_recovery());
             }
 
-            self.expect(TokenKind::RightBrace, "Expected '}' after else body")?;
+            self.expect_closing_brace("Expected '}' after else body");
 
             Some(Box::new(Expr::Block(else_stmts)))
         } else {
@@ -485,6 +594,14 @@ pub fn parse_source(source: &str) -> Result<Program, String> {
     parser.parse()
 }
 
+/// Helper function to parse from source string, collecting every syntax error
+pub fn parse_source_with_recovery(source: &str) -> (Program, Vec<String>) {
+    let mut lexer = Lexer::new(source);
+    let tokens = lexer.tokenize();
+    let mut parser = Parser::new(tokens);
+    parser.parse_with_recovery()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -647,4 +764,157 @@ mod tests {
             _ => panic!("Expected Let statement"),
         }
     }
+
+    #[test]
+    fn test_recovery_reports_multiple_errors() {
+        let source = r#"
+            let a = ;
+            let b = 2;
+            let = 3;
+            let c = 4;
+        "#;
+        let (program, errors) = parse_source_with_recovery(source);
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(program.stmts.len(), 4);
+
+        // Malformed initializer keeps the binding with a placeholder value
+        assert_eq!(
+            program.stmts[0],
+            Stmt::Let {
+                name: "a".to_string(),
+                value: Expr::Error,
+            }
+        );
+        assert!(matches!(program.stmts[1], Stmt::Let { .. }));
+        assert_eq!(program.stmts[2], Stmt::Error);
+        assert!(matches!(program.stmts[3], Stmt::Let { .. }));
+    }
+
+    #[test]
+    fn test_recovery_inside_function_body() {
+        let source = r#"
+            fn main() {
+                return 1 + ;
+                let x = 2;
+            }
+            let y = 3;
+        "#;
+        let (program, errors) = parse_source_with_recovery(source);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(program.stmts.len(), 2);
+
+        match &program.stmts[0] {
+            Stmt::Fn { body, .. } => {
+                assert_eq!(body.len(), 2);
+                assert_eq!(body[0], Stmt::Error);
+                assert!(matches!(body[1], Stmt::Let { .. }));
+            }
+            _ => panic!("Expected Fn statement"),
+        }
+    }
+
+    #[test]
+    fn test_recovery_keeps_unclosed_function_at_eof() {
+        let (program, errors) = parse_source_with_recovery("fn f() { let a = ; let b = 2;");
+
+        assert_eq!(errors.len(), 2); // malformed initializer + missing '}'
+        assert_eq!(program.stmts.len(), 1);
+
+        match &program.stmts[0] {
+            Stmt::Fn { name, body, .. } => {
+                assert_eq!(name, "f");
+                assert_eq!(body.len(), 2);
+                assert_eq!(
+                    body[0],
+                    Stmt::Let {
+                        name: "a".to_string(),
+                        value: Expr::Error,
+                    }
+                );
+                assert!(matches!(body[1], Stmt::Let { .. }));
+            }
+            _ => panic!("Expected Fn statement"),
+        }
+    }
+
+    #[test]
+    fn test_recovery_keeps_unclosed_if_inside_function() {
+        let (program, errors) = parse_source_with_recovery("fn f() { if c { let a = 1;");
+
+        assert!(!errors.is_empty());
+        assert_eq!(program.stmts.len(), 1);
+
+        match &program.stmts[0] {
+            Stmt::Fn { body, .. } => {
+                assert_eq!(body.len(), 1);
+                match &body[0] {
+                    Stmt::Expr(Expr::If { then_branch, .. }) => match &**then_branch {
+                        Expr::Block(stmts) => assert_eq!(stmts.len(), 1),
+                        _ => panic!("Expected block"),
+                    },
+                    _ => panic!("Expected if expression statement"),
+                }
+            }
+            _ => panic!("Expected Fn statement"),
+        }
+    }
+
+    #[test]
+    fn test_recovery_skips_stray_closing_brace() {
+        let (program, errors) = parse_source_with_recovery("} let x = 1;");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(program.stmts.len(), 2);
+        assert_eq!(program.stmts[0], Stmt::Error);
+    }
+
+    #[test]
+    fn test_recovery_keeps_statement_missing_semicolon() {
+        let source = r#"
+            let y = 42
+            foo(1);
+            let z = y;
+        "#;
+        let (program, errors) = parse_source_with_recovery(source);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(program.stmts.len(), 3);
+        assert_eq!(
+            program.stmts[0],
+            Stmt::Let {
+                name: "y".to_string(),
+                value: Expr::Number(42),
+            }
+        );
+        assert!(matches!(program.stmts[1], Stmt::Expr(Expr::Call { .. })));
+        assert!(matches!(program.stmts[2], Stmt::Let { .. }));
+    }
+
+    #[test]
+    fn test_recovery_skips_block_after_bad_fn_header() {
+        let source = "fn main( { let x = 1; return x; } let z = 2;";
+        let (program, errors) = parse_source_with_recovery(source);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(program.stmts.len(), 2);
+        assert_eq!(program.stmts[0], Stmt::Error);
+        assert!(matches!(program.stmts[1], Stmt::Let { .. }));
+    }
+
+    #[test]
+    fn test_recovery_skips_block_in_malformed_initializer() {
+        let (program, errors) = parse_source_with_recovery("let x = if { 1; }; let y = 2;");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(program.stmts.len(), 2);
+        assert_eq!(
+            program.stmts[0],
+            Stmt::Let {
+                name: "x".to_string(),
+                value: Expr::Error,
+            }
+        );
+    }
 }
@@ -3,7 +3,7 @@
 /// JIRA: VELA-565 (Sprint 4)
 /// Tests completos end-to-end del lexer + parser
 
-use vela_prototypes::parse_source;
+use vela_prototypes::{parse_source, parse_source_with_recovery, Expr, Stmt};
 
 #[test]
 fn test_hello_world() {
@@ -114,3 +114,27 @@ fn test_error_missing_semicolon() {
     let program = parse_source(source);
     assert!(program.is_err());
 }
+
+#[test]
+fn test_error_recovery_continues_after_syntax_error() {
+    let source = r#"
+        let x = ;
+        fn main() {
+            return "still parsed";
+        }
+        let y = 42
+    "#;
+
+    let (program, errors) = parse_source_with_recovery(source);
+    assert_eq!(errors.len(), 2); // malformed initializer + missing ';'
+    assert_eq!(program.stmts.len(), 3); // let + fn + let
+
+    // The binding missing its ';' is kept intact
+    assert_eq!(
+        program.stmts[2],
+        Stmt::Let {
+            name: "y".to_string(),
+            value: Expr::Number(42),
+        }
+    );
+}